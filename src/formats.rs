@@ -1,8 +1,9 @@
 //! Simple predefined formats for use with [`refmt()`](crate::refmt).
 
 use core::fmt;
+use core::fmt::Write as _;
 
-use crate::Fmt;
+use crate::{Fmt, Refmt};
 
 /// [`Fmt`] format type which forces a string to be unquoted inside [`fmt::Debug`].
 ///
@@ -38,3 +39,552 @@ impl Fmt<Unquote> for str {
         write!(fmt, "{self}")
     }
 }
+
+/// Returns a value implementing [`fmt::Display`] which writes `value`'s [`fmt::Display`] output
+/// with every non-empty line indented by `indent` spaces. Empty lines are left without indentation.
+///
+/// This is useful when implementing recursive, tree-shaped [`Fmt`] formats, to indent each level
+/// of nesting relative to its parent, analogous to how `{:#?}` indents the fields of a
+/// `#[derive(Debug)]` struct.
+///
+/// Note that this does not implement [`Fmt`] itself: a blanket `impl<T: Display> Fmt<Indented>
+/// for T` would conflict with this crate's reference-forwarding impls of `Fmt` for `&T`/`&mut T`
+/// (since `&U: Display` whenever `U: Display`), so instead `value` is borrowed explicitly here.
+///
+/// # Example
+///
+/// ```
+/// use core::fmt;
+/// use manyfmt::formats::indented;
+///
+/// struct Branch(&'static str, Vec<Branch>);
+///
+/// impl fmt::Display for Branch {
+///     fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         writeln!(fmt, "{}", self.0)?;
+///         for child in &self.1 {
+///             write!(fmt, "{}", indented(2, child))?;
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let tree = Branch("a", vec![Branch("b", vec![]), Branch("c", vec![])]);
+/// assert_eq!(tree.to_string(), "a\n  b\n  c\n");
+/// ```
+pub fn indented<T: ?Sized + fmt::Display>(indent: usize, value: &T) -> Indented<'_, T> {
+    Indented { indent, value }
+}
+
+/// Return type of [`indented()`].
+#[derive(Debug)]
+pub struct Indented<'a, T: ?Sized> {
+    indent: usize,
+    value: &'a T,
+}
+
+// Written by hand rather than `#[derive(Clone, Copy)]`: the derive would add `T: Clone`/`T:
+// Copy` bounds, but `&'a T` is `Clone`/`Copy` regardless of `T`, so this type should be too.
+impl<T: ?Sized> Clone for Indented<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+impl<T: ?Sized> Copy for Indented<'_, T> {}
+
+impl<T: ?Sized + fmt::Display> fmt::Display for Indented<'_, T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut adapter = IndentedWriter {
+            inner: fmt,
+            indent: self.indent,
+            on_newline: true,
+        };
+        write!(adapter, "{}", self.value)
+    }
+}
+
+/// [`fmt::Write`] adapter which writes an indent of [`Self::indent`] spaces at the start of
+/// every non-empty line written through it, mirroring the pattern used by `core::fmt`'s internal
+/// `PadAdapter` (used to implement `{:#?}`).
+struct IndentedWriter<'a, 'b> {
+    inner: &'a mut fmt::Formatter<'b>,
+    indent: usize,
+    /// Whether the next non-empty segment written should be preceded by the indent.
+    on_newline: bool,
+}
+
+impl IndentedWriter<'_, '_> {
+    fn write_segment(&mut self, segment: &str) -> fmt::Result {
+        if segment.is_empty() {
+            // Don't indent an empty segment (a blank line, or the empty segment after a
+            // terminating newline); that would leave a dangling indent.
+            return Ok(());
+        }
+        if self.on_newline {
+            for _ in 0..self.indent {
+                self.inner.write_char(' ')?;
+            }
+            self.on_newline = false;
+        }
+        self.inner.write_str(segment)
+    }
+}
+
+impl fmt::Write for IndentedWriter<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let mut lines = s.split('\n');
+        if let Some(first) = lines.next() {
+            self.write_segment(first)?;
+        }
+        for line in lines {
+            self.inner.write_char('\n')?;
+            self.on_newline = true;
+            self.write_segment(line)?;
+        }
+        Ok(())
+    }
+}
+
+/// Returns a value implementing [`fmt::Display`] which formats `value` using `fun`, without
+/// having to declare a format-selector struct and write a [`Fmt`] impl for it.
+///
+/// Any state the closure needs (equivalent to the usual `fopt` parameter of [`Fmt::fmt()`]) can
+/// simply be captured by the closure.
+///
+/// Note that, unlike a hand-written [`Fmt`] impl, the value returned by this function cannot
+/// itself be used as the `F` of a `Fmt<F>` impl via [`Refmt::refmt()`]: there is no
+/// coherence-legal way for a from-closure helper to double as the format-selector type (see
+/// [`indented()`]'s documentation for why), so `value` is borrowed explicitly here instead, the
+/// same way [`indented()`] and [`padded()`] do.
+///
+/// [`Refmt::refmt()`]: crate::Refmt::refmt
+///
+/// # Example
+///
+/// ```
+/// use manyfmt::formats::from_fn;
+///
+/// let suffix = "!";
+/// let value = 42;
+/// let text = from_fn(&value, |v: &i32, fmt| write!(fmt, "{v}{suffix}")).to_string();
+/// assert_eq!(text, "42!");
+/// ```
+pub fn from_fn<T, Fun>(value: &T, fun: Fun) -> FromFn<'_, T, Fun>
+where
+    T: ?Sized,
+    Fun: Fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    FromFn { value, fun }
+}
+
+/// Return type of [`from_fn()`].
+#[derive(Debug)]
+pub struct FromFn<'a, T: ?Sized, Fun> {
+    value: &'a T,
+    fun: Fun,
+}
+
+// Written by hand rather than `#[derive(Clone, Copy)]`: the derive would add a spurious `T:
+// Clone`/`T: Copy` bound from the `&'a T` field, same as [`Indented`].
+impl<T: ?Sized, Fun: Clone> Clone for FromFn<'_, T, Fun> {
+    fn clone(&self) -> Self {
+        FromFn {
+            value: self.value,
+            fun: self.fun.clone(),
+        }
+    }
+}
+impl<T: ?Sized, Fun: Copy> Copy for FromFn<'_, T, Fun> {}
+
+impl<T, Fun> fmt::Display for FromFn<'_, T, Fun>
+where
+    T: ?Sized,
+    Fun: Fn(&T, &mut fmt::Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        (self.fun)(self.value, fmt)
+    }
+}
+
+/// Returns a value implementing [`fmt::Display`] which buffers `value`'s [`fmt::Display`]
+/// output and re-emits it through [`fmt::Formatter::pad()`], so that it honors the `width`,
+/// `fill`, `align`, and `precision` flags requested by the caller (e.g. `format!("{:>8}", ...)`),
+/// which a hand-written multi-call [`Fmt`] implementation cannot apply to its combined output on
+/// its own. Apply [`Refmt::refmt()`] first to pad the output of a [`Fmt`] format.
+///
+/// Requires the `alloc` feature, since the inner output must be buffered into a [`String`].
+///
+/// Note that this does not implement [`Fmt`] itself: a blanket `impl<T: Fmt<F>, F> Fmt<Padded<F>>
+/// for T` would conflict with this crate's reference-forwarding impls of `Fmt` for `&T`/`&mut
+/// T`, so instead `value` is borrowed explicitly here.
+///
+/// [`String`]: alloc::string::String
+/// [`Refmt::refmt()`]: crate::Refmt::refmt
+///
+/// # Example
+///
+/// ```
+/// use manyfmt::{Refmt, formats::padded};
+///
+/// struct Greeting;
+///
+/// impl manyfmt::Fmt<Greeting> for str {
+///     fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>, _: &Greeting) -> core::fmt::Result {
+///         write!(fmt, "hi {self}")
+///     }
+/// }
+///
+/// let text = format!("{:*^12}", padded(&"sam".refmt(&Greeting)));
+/// assert_eq!(text, "***hi sam***");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn padded<T: ?Sized + fmt::Display>(value: &T) -> Padded<'_, T> {
+    Padded { value }
+}
+
+/// Return type of [`padded()`].
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct Padded<'a, T: ?Sized> {
+    value: &'a T,
+}
+
+// Written by hand rather than `#[derive(Clone, Copy)]`: the derive would add a spurious `T:
+// Clone`/`T: Copy` bound from the `&'a T` field, same as [`Indented`].
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> Clone for Padded<'_, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+#[cfg(feature = "alloc")]
+impl<T: ?Sized> Copy for Padded<'_, T> {}
+
+#[cfg(feature = "alloc")]
+impl<T: ?Sized + fmt::Display> fmt::Display for Padded<'_, T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if fmt.width().is_none() && fmt.precision().is_none() {
+            // Nothing to pad or truncate, so skip the buffering allocation entirely.
+            return fmt::Display::fmt(self.value, fmt);
+        }
+        let buffer = alloc::string::ToString::to_string(self.value);
+        fmt.pad(&buffer)
+    }
+}
+
+/// Returns a value implementing [`fmt::Debug`] which prints `iter`'s items as a debug list
+/// (`[a, b, c]`), formatting each item via its [`Fmt<F>`](Fmt) implementation rather than its own
+/// [`fmt::Debug`] implementation.
+///
+/// This bridges [`Fmt`] with [`fmt::Formatter::debug_list()`], for the common case of
+/// implementing [`fmt::Debug`] for a collection whose elements should be rendered with a
+/// particular [`Fmt`] format.
+///
+/// # Example
+///
+/// ```
+/// use manyfmt::Fmt;
+/// use manyfmt::formats::debug_list;
+///
+/// struct AsHex;
+///
+/// impl Fmt<AsHex> for i32 {
+///     fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>, _: &AsHex) -> core::fmt::Result {
+///         write!(fmt, "{self:#x}")
+///     }
+/// }
+///
+/// let items = [10, 11, 12];
+/// assert_eq!(format!("{:?}", debug_list(items, &AsHex)), "[0xa, 0xb, 0xc]");
+/// ```
+pub fn debug_list<I, F>(iter: I, fopt: &F) -> DebugList<'_, I, F>
+where
+    I: IntoIterator + Clone,
+    I::Item: Fmt<F>,
+{
+    DebugList { iter, fopt }
+}
+
+/// Return type of [`debug_list()`].
+pub struct DebugList<'a, I, F> {
+    iter: I,
+    fopt: &'a F,
+}
+
+// Written by hand rather than `#[derive(Clone, Copy)]`: the derive would add a spurious `F:
+// Clone`/`F: Copy` bound from the `&'a F` field, same as [`Indented`].
+impl<I: Clone, F> Clone for DebugList<'_, I, F> {
+    fn clone(&self) -> Self {
+        DebugList {
+            iter: self.iter.clone(),
+            fopt: self.fopt,
+        }
+    }
+}
+impl<I: Copy, F> Copy for DebugList<'_, I, F> {}
+
+impl<I, F> fmt::Debug for DebugList<'_, I, F>
+where
+    I: IntoIterator + Clone,
+    I::Item: Fmt<F>,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_list()
+            .entries(self.iter.clone().into_iter().map(|item| Entry(item, self.fopt)))
+            .finish()
+    }
+}
+
+/// Returns a value implementing [`fmt::Debug`] which prints `iter`'s items as a debug set
+/// (`{a, b, c}`), formatting each item via its [`Fmt<F>`](Fmt) implementation rather than its own
+/// [`fmt::Debug`] implementation.
+///
+/// This bridges [`Fmt`] with [`fmt::Formatter::debug_set()`]; see [`debug_list()`] for more
+/// information.
+///
+/// # Example
+///
+/// ```
+/// use manyfmt::Fmt;
+/// use manyfmt::formats::debug_set;
+///
+/// struct AsHex;
+///
+/// impl Fmt<AsHex> for i32 {
+///     fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>, _: &AsHex) -> core::fmt::Result {
+///         write!(fmt, "{self:#x}")
+///     }
+/// }
+///
+/// let items = [10, 11, 12];
+/// assert_eq!(format!("{:?}", debug_set(items, &AsHex)), "{0xa, 0xb, 0xc}");
+/// ```
+pub fn debug_set<I, F>(iter: I, fopt: &F) -> DebugSet<'_, I, F>
+where
+    I: IntoIterator + Clone,
+    I::Item: Fmt<F>,
+{
+    DebugSet { iter, fopt }
+}
+
+/// Return type of [`debug_set()`].
+pub struct DebugSet<'a, I, F> {
+    iter: I,
+    fopt: &'a F,
+}
+
+// Written by hand rather than `#[derive(Clone, Copy)]`: the derive would add a spurious `F:
+// Clone`/`F: Copy` bound from the `&'a F` field, same as [`Indented`].
+impl<I: Clone, F> Clone for DebugSet<'_, I, F> {
+    fn clone(&self) -> Self {
+        DebugSet {
+            iter: self.iter.clone(),
+            fopt: self.fopt,
+        }
+    }
+}
+impl<I: Copy, F> Copy for DebugSet<'_, I, F> {}
+
+impl<I, F> fmt::Debug for DebugSet<'_, I, F>
+where
+    I: IntoIterator + Clone,
+    I::Item: Fmt<F>,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_set()
+            .entries(self.iter.clone().into_iter().map(|item| Entry(item, self.fopt)))
+            .finish()
+    }
+}
+
+/// Returns a value implementing [`fmt::Debug`] which prints `iter`'s key-value pairs as a debug
+/// map (`{k: v, ...}`), formatting each key via its [`Fmt<KF>`](Fmt) implementation and each
+/// value via its [`Fmt<VF>`](Fmt) implementation, rather than their own [`fmt::Debug`]
+/// implementations.
+///
+/// This bridges [`Fmt`] with [`fmt::Formatter::debug_map()`]; see [`debug_list()`] for more
+/// information.
+///
+/// # Example
+///
+/// ```
+/// use manyfmt::Fmt;
+/// use manyfmt::formats::debug_map;
+///
+/// struct AsHex;
+///
+/// impl Fmt<AsHex> for i32 {
+///     fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>, _: &AsHex) -> core::fmt::Result {
+///         write!(fmt, "{self:#x}")
+///     }
+/// }
+///
+/// let items = [(1, 10), (2, 11)];
+/// assert_eq!(format!("{:?}", debug_map(items, &AsHex, &AsHex)), "{0x1: 0xa, 0x2: 0xb}");
+/// ```
+pub fn debug_map<'k, 'v, I, K, V, KF, VF>(
+    iter: I,
+    key_fopt: &'k KF,
+    value_fopt: &'v VF,
+) -> DebugMap<'k, 'v, I, KF, VF>
+where
+    I: IntoIterator<Item = (K, V)> + Clone,
+    K: Fmt<KF>,
+    V: Fmt<VF>,
+{
+    DebugMap {
+        iter,
+        key_fopt,
+        value_fopt,
+    }
+}
+
+/// Return type of [`debug_map()`].
+pub struct DebugMap<'k, 'v, I, KF, VF> {
+    iter: I,
+    key_fopt: &'k KF,
+    value_fopt: &'v VF,
+}
+
+// Written by hand rather than `#[derive(Clone, Copy)]`: the derive would add spurious `KF:
+// Clone`/`VF: Clone` (and `Copy`) bounds from the `&'k KF`/`&'v VF` fields, same as [`Indented`].
+impl<I: Clone, KF, VF> Clone for DebugMap<'_, '_, I, KF, VF> {
+    fn clone(&self) -> Self {
+        DebugMap {
+            iter: self.iter.clone(),
+            key_fopt: self.key_fopt,
+            value_fopt: self.value_fopt,
+        }
+    }
+}
+impl<I: Copy, KF, VF> Copy for DebugMap<'_, '_, I, KF, VF> {}
+
+impl<I, K, V, KF, VF> fmt::Debug for DebugMap<'_, '_, I, KF, VF>
+where
+    I: IntoIterator<Item = (K, V)> + Clone,
+    K: Fmt<KF>,
+    V: Fmt<VF>,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_map()
+            .entries(
+                self.iter
+                    .clone()
+                    .into_iter()
+                    .map(|(k, v)| (Entry(k, self.key_fopt), Entry(v, self.value_fopt))),
+            )
+            .finish()
+    }
+}
+
+/// Wraps a value together with a `Fmt<F>` format selector so that it implements [`fmt::Debug`]
+/// by delegating to [`Fmt::fmt()`]. Used internally by [`debug_list()`], [`debug_set()`], and
+/// [`debug_map()`] to feed items into the `std` debug-builders, which require [`fmt::Debug`]
+/// rather than [`Fmt`].
+struct Entry<'a, T, F>(T, &'a F);
+
+impl<T: Fmt<F>, F> fmt::Debug for Entry<'_, T, F> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Fmt::fmt(&self.0, fmt, self.1)
+    }
+}
+
+/// [`fmt::Display`] adapter which formats `iter`'s items, each via its [`Fmt<F>`](Fmt)
+/// implementation, joined by [`Self::sep`] with no trailing separator.
+///
+/// This covers the common need to render a slice or other collection "joined by X using format
+/// Y", without having to hand-roll a loop with first-element tracking.
+///
+/// Note that this implements [`fmt::Display`] directly, rather than [`Fmt<F>`] for its own `F`:
+/// a blanket `impl<I: Clone + IntoIterator, F> Fmt<Separated<F>> for I` would conflict with this
+/// crate's reference-forwarding impls of `Fmt` for `&T`/`&mut T` (e.g. `&Vec<X>` satisfies
+/// `Clone + IntoIterator` too), so instead the iterable is held directly by this struct.
+///
+/// It does, however, implement [`Fmt<G>`](Fmt) *for every `G`*, by ignoring the supplied `fopt`
+/// and delegating to its own [`fmt::Display`] impl above — unlike the blanket impl ruled out
+/// above, this is not generic over `Self`, so it does not conflict with the reference-forwarding
+/// impls. This is what makes `Separated` usable recursively: an item can itself be a `Separated`,
+/// as shown below.
+///
+/// # Example
+///
+/// ```
+/// use manyfmt::Fmt;
+/// use manyfmt::formats::Separated;
+///
+/// struct AsHex;
+///
+/// impl Fmt<AsHex> for i32 {
+///     fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>, _: &AsHex) -> core::fmt::Result {
+///         write!(fmt, "{self:#x}")
+///     }
+/// }
+///
+/// let items = [10, 11, 12];
+/// let text = Separated { sep: ", ", inner: AsHex, iter: items }.to_string();
+/// assert_eq!(text, "0xa, 0xb, 0xc");
+/// ```
+///
+/// Nesting a `Separated` as another `Separated`'s item, to join rows of columns:
+///
+/// ```
+/// use manyfmt::Fmt;
+/// use manyfmt::formats::Separated;
+///
+/// #[derive(Clone, Copy)]
+/// struct AsHex;
+///
+/// impl Fmt<AsHex> for i32 {
+///     fn fmt(&self, fmt: &mut core::fmt::Formatter<'_>, _: &AsHex) -> core::fmt::Result {
+///         write!(fmt, "{self:#x}")
+///     }
+/// }
+///
+/// let text = Separated {
+///     sep: "; ",
+///     inner: AsHex,
+///     iter: [
+///         Separated { sep: ", ", inner: AsHex, iter: [1, 2] },
+///         Separated { sep: ", ", inner: AsHex, iter: [3, 4] },
+///     ],
+/// }
+/// .to_string();
+/// assert_eq!(text, "0x1, 0x2; 0x3, 0x4");
+/// ```
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+#[allow(clippy::exhaustive_structs)]
+pub struct Separated<'s, F, I> {
+    /// String written between each pair of consecutive items.
+    pub sep: &'s str,
+    /// [`Fmt`] format applied to each item.
+    pub inner: F,
+    /// Items to join together.
+    pub iter: I,
+}
+
+impl<I, F> fmt::Display for Separated<'_, F, I>
+where
+    I: Clone + IntoIterator,
+    I::Item: Fmt<F>,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut iter = self.iter.clone().into_iter();
+        if let Some(first) = iter.next() {
+            write!(fmt, "{}", first.refmt(&self.inner))?;
+        }
+        for item in iter {
+            write!(fmt, "{}{}", self.sep, item.refmt(&self.inner))?;
+        }
+        Ok(())
+    }
+}
+
+impl<G: ?Sized, F, I> Fmt<G> for Separated<'_, F, I>
+where
+    I: Clone + IntoIterator,
+    I::Item: Fmt<F>,
+{
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>, _fopt: &G) -> fmt::Result {
+        fmt::Display::fmt(self, fmt)
+    }
+}
@@ -82,6 +82,9 @@
 #[macro_use]
 extern crate std;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 use core::fmt;
 
 pub mod formats;